@@ -1,3 +1,5 @@
+mod webhook;
+
 use reqwest::{Client, Method};
 use rmcp::{
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
@@ -10,6 +12,17 @@ use rmcp::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// A cached response along with the freshness bookkeeping needed to decide
+/// whether to reuse it or revalidate it with `If-None-Match`.
+struct CacheEntry {
+    inserted: Instant,
+    value: Value,
+    etag: Option<String>,
+}
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetPrParams {
@@ -34,13 +47,109 @@ pub struct GetCommentsParams {
     pub owner: String,
     pub repo: String,
     pub number: u64,
+    /// Results per page (GitHub caps this at 100).
+    pub per_page: Option<u64>,
+    /// Stop paginating after this many pages, even if more are available.
+    pub max_pages: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CreateGistParams {
+    pub description: Option<String>,
+    pub public: bool,
+    /// Map of filename to file content.
+    pub files: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetGistParams {
+    /// Either a bare gist id or a https://gist.github.com/[user/]<id> URL.
+    pub gist: String,
+}
+
+/// Pull the trailing hex id out of either a bare gist id or a
+/// `https://gist.github.com/[user/]<id>` URL.
+fn extract_gist_id(gist: &str) -> &str {
+    gist.trim_end_matches('/').rsplit('/').next().unwrap_or(gist)
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CreateIssueParams {
+    pub owner: String,
+    pub repo: String,
+    pub title: String,
+    pub body: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub assignees: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AddCommentParams {
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReviewComment {
+    pub path: String,
+    pub line: u64,
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CreateReviewParams {
+    pub owner: String,
+    pub repo: String,
+    pub pull_number: u64,
+    /// One of `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`.
+    pub event: String,
+    pub body: Option<String>,
+    pub comments: Option<Vec<ReviewComment>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct VerifyWebhookParams {
+    /// Raw, unparsed request body exactly as delivered by GitHub.
+    pub body: String,
+    /// The `X-Hub-Signature-256` header value, e.g. `sha256=...`.
+    pub signature: String,
+    /// Defaults to the `GITHUB_WEBHOOK_SECRET` environment variable.
+    pub secret: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ParsePushEventParams {
+    /// The JSON body of a verified `push` webhook delivery.
+    pub payload: Value,
+}
+
+/// Parse the `rel="next"` URL out of an RFC 5988 `Link` header, e.g.
+/// `<https://api.github.com/...&page=2>; rel="next", <...>; rel="last"`.
+fn parse_next_link(link_header: &str) -> Option<String> {
+    link_header.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url_segment = segments.next()?.trim();
+        let is_next = segments.any(|s| s.trim() == r#"rel="next""#);
+        if !is_next {
+            return None;
+        }
+        url_segment
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .map(|s| s.to_string())
+    })
 }
 
 #[derive(Clone)]
 pub struct GithubServer {
     tool_router: ToolRouter<Self>,
     client: Client,
+    instance_url: String,
     token: Option<String>,
+    cache: Arc<RwLock<HashMap<String, CacheEntry>>>,
+    cache_ttl: Duration,
 }
 
 impl Default for GithubServer {
@@ -57,22 +166,157 @@ impl GithubServer {
             .or_else(|_| std::env::var("GH_TOKEN"))
             .ok();
 
+        let instance_url = std::env::var("GITHUB_API_URL")
+            .unwrap_or_else(|_| "https://api.github.com".to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        // Allow talking to Enterprise/Gitea instances that sit behind a
+        // private CA without a full cert chain installed on the host.
+        let allow_insecure = std::env::var("GITHUB_ALLOW_INSECURE_TLS")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+
+        let cache_ttl = std::env::var("GITHUB_CACHE_TTL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| Duration::from_secs(60));
+
         Self {
             tool_router: Self::tool_router(),
             client: Client::builder()
                 .user_agent("goose-github-mcp/1.0")
+                .danger_accept_invalid_certs(allow_insecure)
                 .build()
                 .unwrap(),
+            instance_url,
             token,
+            cache: Arc::new(RwLock::new(HashMap::new())),
+            cache_ttl,
         }
     }
 
-    async fn request(&self, method: Method, url: &str) -> Result<Value, ErrorData> {
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.instance_url, path.trim_start_matches('/'))
+    }
+
+    /// Drop a cached `GET` response so the next read reflects a write that
+    /// just landed on the same resource, instead of serving a stale entry
+    /// for up to `cache_ttl`.
+    fn invalidate_cache_entry(&self, path: &str) {
+        let key = format!("{} {}", Method::GET, self.url(path));
+        self.cache.write().unwrap().remove(&key);
+    }
+
+    async fn request(&self, method: Method, path: &str) -> Result<Value, ErrorData> {
+        let url = self.url(path);
+        let cache_key = format!("{} {}", method, url);
+
+        if let Some(entry) = self.cache.read().unwrap().get(&cache_key) {
+            if entry.inserted.elapsed() < self.cache_ttl {
+                return Ok(entry.value.clone());
+            }
+        }
+        let known_etag = self
+            .cache
+            .read()
+            .unwrap()
+            .get(&cache_key)
+            .and_then(|entry| entry.etag.clone());
+
         let mut req = self
             .client
-            .request(method, url)
+            .request(method, &url)
             .header("Accept", "application/vnd.github.v3+json");
 
+        if let Some(token) = &self.token {
+            req = req.header("Authorization", format!("Bearer {}", token));
+        }
+        if let Some(etag) = &known_etag {
+            req = req.header("If-None-Match", etag.as_str());
+        }
+
+        let resp = req.send().await.map_err(|e| {
+            ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("Failed to send request: {}", e),
+                None,
+            )
+        })?;
+
+        let status = resp.status();
+
+        // A 304 means our cached body is still valid; just bump its
+        // timestamp instead of re-downloading and re-parsing it.
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            let mut cache = self.cache.write().unwrap();
+            if let Some(entry) = cache.get_mut(&cache_key) {
+                entry.inserted = Instant::now();
+                return Ok(entry.value.clone());
+            }
+        }
+
+        let etag = resp
+            .headers()
+            .get("etag")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        let text = resp.text().await.map_err(|e| {
+            ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("Failed to read response body: {}", e),
+                None,
+            )
+        })?;
+
+        if !status.is_success() {
+            return Err(ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("GitHub API Error {}: {}", status, text),
+                None,
+            ));
+        }
+
+        let value: Value = serde_json::from_str(&text).map_err(|e| {
+            ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("Failed to parse JSON response: {}", e),
+                None,
+            )
+        })?;
+
+        self.cache.write().unwrap().insert(
+            cache_key,
+            CacheEntry {
+                inserted: Instant::now(),
+                value: value.clone(),
+                etag,
+            },
+        );
+
+        Ok(value)
+    }
+
+    /// Like `request`, but sends a JSON body and bypasses the cache, for the
+    /// write-side (`POST`/`PUT`) tools that mutate GitHub state. `invalidate`
+    /// lists the `GET` paths (e.g. the issue/PR this write lands on) whose
+    /// cached entries should be evicted once the write succeeds.
+    async fn request_with_body(
+        &self,
+        method: Method,
+        path: &str,
+        body: Value,
+        invalidate: &[&str],
+    ) -> Result<Value, ErrorData> {
+        let url = self.url(path);
+        let mut req = self
+            .client
+            .request(method, &url)
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&body);
+
         if let Some(token) = &self.token {
             req = req.header("Authorization", format!("Bearer {}", token));
         }
@@ -102,6 +346,14 @@ impl GithubServer {
             ));
         }
 
+        for path in invalidate {
+            self.invalidate_cache_entry(path);
+        }
+
+        if text.is_empty() {
+            return Ok(Value::Null);
+        }
+
         serde_json::from_str(&text).map_err(|e| {
             ErrorData::new(
                 ErrorCode::INTERNAL_ERROR,
@@ -111,8 +363,83 @@ impl GithubServer {
         })
     }
 
-    // Using raw string url for now, but we could use url crate
-    // https://api.github.com
+    /// Like `request`, but follows `Link: rel="next"` pagination until GitHub
+    /// stops returning a next page (or `max_pages` is hit), concatenating
+    /// each page's JSON array into one.
+    async fn request_paginated(
+        &self,
+        method: Method,
+        path: &str,
+        max_pages: Option<usize>,
+    ) -> Result<Value, ErrorData> {
+        let mut url = self.url(path);
+        let mut items = Vec::new();
+        let mut pages = 0usize;
+
+        loop {
+            let mut req = self
+                .client
+                .request(method.clone(), &url)
+                .header("Accept", "application/vnd.github.v3+json");
+
+            if let Some(token) = &self.token {
+                req = req.header("Authorization", format!("Bearer {}", token));
+            }
+
+            let resp = req.send().await.map_err(|e| {
+                ErrorData::new(
+                    ErrorCode::INTERNAL_ERROR,
+                    format!("Failed to send request: {}", e),
+                    None,
+                )
+            })?;
+
+            let status = resp.status();
+            let next_url = resp
+                .headers()
+                .get("link")
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_next_link);
+
+            let text = resp.text().await.map_err(|e| {
+                ErrorData::new(
+                    ErrorCode::INTERNAL_ERROR,
+                    format!("Failed to read response body: {}", e),
+                    None,
+                )
+            })?;
+
+            if !status.is_success() {
+                return Err(ErrorData::new(
+                    ErrorCode::INTERNAL_ERROR,
+                    format!("GitHub API Error {}: {}", status, text),
+                    None,
+                ));
+            }
+
+            let page: Value = serde_json::from_str(&text).map_err(|e| {
+                ErrorData::new(
+                    ErrorCode::INTERNAL_ERROR,
+                    format!("Failed to parse JSON response: {}", e),
+                    None,
+                )
+            })?;
+
+            match page {
+                Value::Array(page_items) => items.extend(page_items),
+                other => return Ok(other),
+            }
+
+            pages += 1;
+
+            match next_url {
+                Some(next) if max_pages.map(|max| pages < max).unwrap_or(true) => url = next,
+                _ => break,
+            }
+        }
+
+        Ok(Value::Array(items))
+    }
 
     #[tool(
         name = "github_get_pr",
@@ -122,11 +449,11 @@ impl GithubServer {
         &self,
         params: Parameters<GetPrParams>,
     ) -> Result<CallToolResult, ErrorData> {
-        let url = format!(
-            "https://api.github.com/repos/{}/{}/pulls/{}",
+        let path = format!(
+            "repos/{}/{}/pulls/{}",
             params.0.owner, params.0.repo, params.0.pull_number
         );
-        let json = self.request(Method::GET, &url).await?;
+        let json = self.request(Method::GET, &path).await?;
 
         // We might want to filter this json to be more concise for the LLM
         // For now, returning the full JSON is a safe bet, though large
@@ -144,10 +471,10 @@ impl GithubServer {
         &self,
         params: Parameters<GetPrParams>,
     ) -> Result<CallToolResult, ErrorData> {
-        let url = format!(
-            "https://api.github.com/repos/{}/{}/pulls/{}",
+        let url = self.url(&format!(
+            "repos/{}/{}/pulls/{}",
             params.0.owner, params.0.repo, params.0.pull_number
-        );
+        ));
 
         let mut req = self
             .client
@@ -190,11 +517,11 @@ impl GithubServer {
         &self,
         params: Parameters<GetIssueParams>,
     ) -> Result<CallToolResult, ErrorData> {
-        let url = format!(
-            "https://api.github.com/repos/{}/{}/issues/{}",
+        let path = format!(
+            "repos/{}/{}/issues/{}",
             params.0.owner, params.0.repo, params.0.issue_number
         );
-        let json = self.request(Method::GET, &url).await?;
+        let json = self.request(Method::GET, &path).await?;
 
         Ok(CallToolResult::success(vec![Content::text(
             serde_json::to_string_pretty(&json).unwrap(),
@@ -203,17 +530,22 @@ impl GithubServer {
 
     #[tool(
         name = "github_get_comments",
-        description = "Get comments on an issue or pull request, filtering out bot comments."
+        description = "Get comments on an issue or pull request, filtering out bot comments. Automatically follows pagination, stopping after max_pages pages when it is set."
     )]
     pub async fn get_comments(
         &self,
         params: Parameters<GetCommentsParams>,
     ) -> Result<CallToolResult, ErrorData> {
-        let url = format!(
-            "https://api.github.com/repos/{}/{}/issues/{}/comments",
+        let mut path = format!(
+            "repos/{}/{}/issues/{}/comments",
             params.0.owner, params.0.repo, params.0.number
         );
-        let json = self.request(Method::GET, &url).await?;
+        if let Some(per_page) = params.0.per_page {
+            path = format!("{}?per_page={}", path, per_page);
+        }
+        let json = self
+            .request_paginated(Method::GET, &path, params.0.max_pages)
+            .await?;
 
         // Filter out bot comments
         let comments = if let Value::Array(items) = json {
@@ -237,6 +569,195 @@ impl GithubServer {
             serde_json::to_string_pretty(&comments).unwrap(),
         )]))
     }
+
+    #[tool(
+        name = "github_create_issue",
+        description = "Create a new issue in a repository."
+    )]
+    pub async fn create_issue(
+        &self,
+        params: Parameters<CreateIssueParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = format!("repos/{}/{}/issues", params.0.owner, params.0.repo);
+        let body = serde_json::json!({
+            "title": params.0.title,
+            "body": params.0.body,
+            "labels": params.0.labels,
+            "assignees": params.0.assignees,
+        });
+
+        // A new issue has nothing cached yet, so nothing to invalidate.
+        let json = self
+            .request_with_body(Method::POST, &path, body, &[])
+            .await?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&json).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        name = "github_add_comment",
+        description = "Add a comment to an issue or pull request."
+    )]
+    pub async fn add_comment(
+        &self,
+        params: Parameters<AddCommentParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = format!(
+            "repos/{}/{}/issues/{}/comments",
+            params.0.owner, params.0.repo, params.0.number
+        );
+        let body = serde_json::json!({ "body": params.0.body });
+
+        // The number may refer to an issue or a PR; GitHub exposes both
+        // through `issues/{number}`, and PRs additionally through
+        // `pulls/{number}` — invalidate whichever one is cached.
+        let issue_path = format!(
+            "repos/{}/{}/issues/{}",
+            params.0.owner, params.0.repo, params.0.number
+        );
+        let pr_path = format!(
+            "repos/{}/{}/pulls/{}",
+            params.0.owner, params.0.repo, params.0.number
+        );
+        let json = self
+            .request_with_body(
+                Method::POST,
+                &path,
+                body,
+                &[issue_path.as_str(), pr_path.as_str()],
+            )
+            .await?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&json).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        name = "github_create_review",
+        description = "Submit a pull request review, optionally with per-line comments. 'event' must be APPROVE, REQUEST_CHANGES, or COMMENT."
+    )]
+    pub async fn create_review(
+        &self,
+        params: Parameters<CreateReviewParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = format!(
+            "repos/{}/{}/pulls/{}/reviews",
+            params.0.owner, params.0.repo, params.0.pull_number
+        );
+        let body = serde_json::json!({
+            "event": params.0.event,
+            "body": params.0.body,
+            "comments": params.0.comments,
+        });
+
+        let pr_path = format!(
+            "repos/{}/{}/pulls/{}",
+            params.0.owner, params.0.repo, params.0.pull_number
+        );
+        let json = self
+            .request_with_body(Method::POST, &path, body, &[pr_path.as_str()])
+            .await?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&json).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        name = "github_create_gist",
+        description = "Create a gist from one or more files, returning its URL."
+    )]
+    pub async fn create_gist(
+        &self,
+        params: Parameters<CreateGistParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let files: HashMap<String, Value> = params
+            .0
+            .files
+            .into_iter()
+            .map(|(name, content)| (name, serde_json::json!({ "content": content })))
+            .collect();
+        let body = serde_json::json!({
+            "description": params.0.description,
+            "public": params.0.public,
+            "files": files,
+        });
+
+        let json = self
+            .request_with_body(Method::POST, "gists", body, &[])
+            .await?;
+        let html_url = json
+            .get("html_url")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+
+        Ok(CallToolResult::success(vec![Content::text(
+            html_url.to_string(),
+        )]))
+    }
+
+    #[tool(
+        name = "github_get_gist",
+        description = "Get a gist's files by id or gist.github.com URL."
+    )]
+    pub async fn get_gist(
+        &self,
+        params: Parameters<GetGistParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = format!("gists/{}", extract_gist_id(&params.0.gist));
+        let json = self.request(Method::GET, &path).await?;
+        let files = json.get("files").cloned().unwrap_or(Value::Null);
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&files).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        name = "github_verify_webhook",
+        description = "Verify a GitHub webhook delivery's X-Hub-Signature-256 against a shared secret, returning whether the payload is authentic."
+    )]
+    pub async fn verify_webhook(
+        &self,
+        params: Parameters<VerifyWebhookParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let secret = params
+            .0
+            .secret
+            .or_else(|| std::env::var("GITHUB_WEBHOOK_SECRET").ok())
+            .ok_or_else(|| {
+                ErrorData::new(
+                    ErrorCode::INVALID_PARAMS,
+                    "no webhook secret provided and GITHUB_WEBHOOK_SECRET is not set".to_string(),
+                    None,
+                )
+            })?;
+
+        let authentic =
+            webhook::verify_signature(&secret, params.0.body.as_bytes(), &params.0.signature);
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::json!({ "authentic": authentic }).to_string(),
+        )]))
+    }
+
+    #[tool(
+        name = "github_parse_push_event",
+        description = "Extract the tip commit SHA, repository, head commit, and pusher from a verified push webhook payload."
+    )]
+    pub async fn parse_push_event(
+        &self,
+        params: Parameters<ParsePushEventParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let summary = webhook::parse_push_event(&params.0.payload)?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&summary).unwrap(),
+        )]))
+    }
 }
 
 #[tool_handler(router = self.tool_router)]
@@ -0,0 +1,84 @@
+use hmac::{Hmac, Mac};
+use rmcp::model::{ErrorCode, ErrorData};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+
+/// A push event, trimmed down to the fields agents actually act on.
+#[derive(Debug, Serialize)]
+pub struct PushEventSummary {
+    pub after: String,
+    pub repository_full_name: String,
+    pub head_commit: Value,
+    pub pusher: Value,
+}
+
+/// Verify a `X-Hub-Signature-256` header against the raw request body using
+/// `HMAC-SHA256(secret, body)`, comparing in constant time to avoid leaking
+/// the valid signature through response-time side channels.
+pub fn verify_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
+    let Some(expected_hex) = signature_header.strip_prefix("sha256=") else {
+        return false;
+    };
+
+    let mut mac = match Hmac::<Sha256>::new_from_slice(secret.as_bytes()) {
+        Ok(mac) => mac,
+        Err(_) => return false,
+    };
+    mac.update(body);
+    let computed_hex = hex::encode(mac.finalize().into_bytes());
+
+    constant_time_eq(computed_hex.as_bytes(), expected_hex.as_bytes())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Extract the fields agents need from a verified push payload, erroring out
+/// precisely on whichever field is missing or the wrong type.
+pub fn parse_push_event(payload: &Value) -> Result<PushEventSummary, ErrorData> {
+    let missing_field = |field: &str| {
+        ErrorData::new(
+            ErrorCode::INVALID_PARAMS,
+            format!("push payload missing or mistyped field '{}'", field),
+            None,
+        )
+    };
+
+    let after = payload
+        .get("after")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| missing_field("after"))?
+        .to_string();
+
+    let repository_full_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| missing_field("repository.full_name"))?
+        .to_string();
+
+    // `head_commit` is null when the push deletes a branch.
+    let head_commit = payload
+        .get("head_commit")
+        .filter(|v| v.is_object() || v.is_null())
+        .cloned()
+        .ok_or_else(|| missing_field("head_commit"))?;
+
+    let pusher = payload
+        .get("pusher")
+        .filter(|v| v.is_object())
+        .cloned()
+        .ok_or_else(|| missing_field("pusher"))?;
+
+    Ok(PushEventSummary {
+        after,
+        repository_full_name,
+        head_commit,
+        pusher,
+    })
+}
@@ -0,0 +1,265 @@
+use reqwest::{Client, Method};
+use rmcp::{
+    handler::server::{router::tool::ToolRouter, wrapper::Parameters},
+    model::{
+        CallToolResult, Content, ErrorCode, ErrorData, Implementation, ServerCapabilities,
+        ServerInfo,
+    },
+    schemars::JsonSchema,
+    tool, tool_handler, tool_router, ServerHandler,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetMrParams {
+    pub owner: String,
+    pub repo: String,
+    pub mr_iid: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetIssueParams {
+    pub owner: String,
+    pub repo: String,
+    pub issue_iid: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetNotesParams {
+    pub owner: String,
+    pub repo: String,
+    /// The merge request or issue IID the notes belong to.
+    pub iid: u64,
+    /// Either "merge_request" or "issue".
+    pub kind: String,
+}
+
+#[derive(Clone)]
+pub struct GitlabServer {
+    tool_router: ToolRouter<Self>,
+    client: Client,
+    instance_url: String,
+    token: Option<String>,
+}
+
+impl Default for GitlabServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[tool_router(router = tool_router)]
+impl GitlabServer {
+    pub fn new() -> Self {
+        let token = std::env::var("GITLAB_TOKEN").ok();
+
+        let instance_url = std::env::var("GITLAB_INSTANCE_URL")
+            .unwrap_or_else(|_| "https://gitlab.com".to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        Self {
+            tool_router: Self::tool_router(),
+            client: Client::builder()
+                .user_agent("goose-gitlab-mcp/1.0")
+                .build()
+                .unwrap(),
+            instance_url,
+            token,
+        }
+    }
+
+    // GitLab's REST v4 API addresses a project by its full namespaced path,
+    // percent-encoded into a single path segment (subgroups included).
+    fn project_path(owner: &str, repo: &str) -> String {
+        format!("{}/{}", owner, repo).replace('/', "%2F")
+    }
+
+    async fn request(&self, method: Method, path: &str) -> Result<Value, ErrorData> {
+        let url = format!("{}/api/v4/{}", self.instance_url, path);
+        let mut req = self
+            .client
+            .request(method, &url)
+            .header("Accept", "application/json");
+
+        if let Some(token) = &self.token {
+            req = req.header("PRIVATE-TOKEN", token);
+        }
+
+        let resp = req.send().await.map_err(|e| {
+            ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("Failed to send request: {}", e),
+                None,
+            )
+        })?;
+
+        let status = resp.status();
+        let text = resp.text().await.map_err(|e| {
+            ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("Failed to read response body: {}", e),
+                None,
+            )
+        })?;
+
+        if !status.is_success() {
+            return Err(ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("GitLab API Error {}: {}", status, text),
+                None,
+            ));
+        }
+
+        serde_json::from_str(&text).map_err(|e| {
+            ErrorData::new(
+                ErrorCode::INTERNAL_ERROR,
+                format!("Failed to parse JSON response: {}", e),
+                None,
+            )
+        })
+    }
+
+    #[tool(
+        name = "gitlab_get_mr",
+        description = "Get details about a merge request, including title, body, and state."
+    )]
+    pub async fn get_mr(
+        &self,
+        params: Parameters<GetMrParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = format!(
+            "projects/{}/merge_requests/{}",
+            Self::project_path(&params.0.owner, &params.0.repo),
+            params.0.mr_iid
+        );
+        let json = self.request(Method::GET, &path).await?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&json).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        name = "gitlab_get_mr_diff",
+        description = "Get the diff content of a merge request."
+    )]
+    pub async fn get_mr_diff(
+        &self,
+        params: Parameters<GetMrParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = format!(
+            "projects/{}/merge_requests/{}/changes",
+            Self::project_path(&params.0.owner, &params.0.repo),
+            params.0.mr_iid
+        );
+        let json = self.request(Method::GET, &path).await?;
+
+        let diff = json
+            .get("changes")
+            .and_then(|c| c.as_array())
+            .map(|changes| {
+                changes
+                    .iter()
+                    .filter_map(|c| c.get("diff").and_then(|d| d.as_str()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default();
+
+        Ok(CallToolResult::success(vec![Content::text(diff)]))
+    }
+
+    #[tool(
+        name = "gitlab_get_issue",
+        description = "Get details about an issue."
+    )]
+    pub async fn get_issue(
+        &self,
+        params: Parameters<GetIssueParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let path = format!(
+            "projects/{}/issues/{}",
+            Self::project_path(&params.0.owner, &params.0.repo),
+            params.0.issue_iid
+        );
+        let json = self.request(Method::GET, &path).await?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&json).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        name = "gitlab_get_notes",
+        description = "Get notes (comments) on a merge request or issue, filtering out system notes. Notes written by bot users are not filtered out."
+    )]
+    pub async fn get_notes(
+        &self,
+        params: Parameters<GetNotesParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let resource = match params.0.kind.as_str() {
+            "merge_request" => "merge_requests",
+            "issue" => "issues",
+            other => {
+                return Err(ErrorData::new(
+                    ErrorCode::INVALID_PARAMS,
+                    format!("kind must be 'merge_request' or 'issue', got '{}'", other),
+                    None,
+                ))
+            }
+        };
+
+        let path = format!(
+            "projects/{}/{}/{}/notes",
+            Self::project_path(&params.0.owner, &params.0.repo),
+            resource,
+            params.0.iid
+        );
+        let json = self.request(Method::GET, &path).await?;
+
+        // Filter out system notes (GitLab's automated notes for things like
+        // label/assignee changes); the embedded `author` is the basic user
+        // object and carries no `bot` field to key on.
+        let notes = if let Value::Array(items) = json {
+            let filtered: Vec<Value> = items
+                .into_iter()
+                .filter(|note| {
+                    note.get("system")
+                        .and_then(|s| s.as_bool())
+                        .map(|system| !system)
+                        .unwrap_or(true)
+                })
+                .collect();
+            Value::Array(filtered)
+        } else {
+            json
+        };
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&notes).unwrap(),
+        )]))
+    }
+}
+
+#[tool_handler(router = self.tool_router)]
+impl ServerHandler for GitlabServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            server_info: Implementation {
+                name: "goose-gitlab".to_string(),
+                version: env!("CARGO_PKG_VERSION").to_owned(),
+                title: None,
+                icons: None,
+                website_url: None,
+            },
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            instructions: Some(
+                "Tools for interacting with GitLab, including viewing merge requests and Issues."
+                    .to_string(),
+            ),
+            ..Default::default()
+        }
+    }
+}